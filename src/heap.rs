@@ -0,0 +1,180 @@
+//! The heap-based variant of [`moore_hodgson`](crate::moore_hodgson).
+
+use alloc::collections::BinaryHeap;
+use alloc::vec;
+use core::cmp::Ordering;
+use core::ops::{Add, Sub};
+
+/// An entry in the processing-time max-heap used by [`moore_hodgson_heap`].
+///
+/// Orders by `processing_time` only, falling back to "equal" for incomparable values so that
+/// [`BinaryHeap`] never panics.
+struct HeapEntry<P> {
+    processing_time: P,
+    index: usize,
+}
+
+impl<P: PartialEq> PartialEq for HeapEntry<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.processing_time == other.processing_time
+    }
+}
+
+impl<P: PartialEq> Eq for HeapEntry<P> {}
+
+impl<P: PartialOrd> Ord for HeapEntry<P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.processing_time
+            .partial_cmp(&other.processing_time)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<P: PartialOrd> PartialOrd for HeapEntry<P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Run an `O(n log n)` variant of the Moore-Hodgson algorithm on the array with items of form
+/// `(item, due_time, processing_time)`. Returns the amount of items that will be on time.
+///
+/// This sorts the items by `due_time` once and then walks them while keeping a max-heap of the
+/// processing times seen so far, evicting the largest whenever the running completion time
+/// overshoots the current item's due time. That eviction step is what makes this the true
+/// (optimal) Moore-Hodgson algorithm: it can find a strictly larger on-time set than
+/// [`moore_hodgson`], whose simpler reject-on-overflow rule never revisits an item it already
+/// accepted and so can settle for a suboptimal one on the same input.
+///
+/// On return, the on-time items occupy the front of the slice in ascending `due_time` order; the
+/// late items, including those with an incomparable `due_time`, occupy the rest of the slice in
+/// an unspecified order.
+///
+/// Note that this function always moves items with a `due_time` that cannot be compared to the
+/// late items. This happens for [`f32::NAN`], for example.
+///
+/// # Complexity
+/// This function runs in `O(n log n)` time.
+///
+/// # Feature
+/// This function requires the `alloc` feature.
+pub fn moore_hodgson_heap<T, D, P>(items: &mut [(T, D, P)]) -> usize
+where
+    D: Clone + PartialOrd,
+    P: Clone + Add<P, Output = P> + Sub<P, Output = P> + Default + PartialOrd<D> + PartialOrd,
+{
+    // Sort by due_time ascending. Items with an incomparable due_time settle at the back,
+    // mirroring `moore_hodgson`'s NaN handling.
+    items.sort_by(|a, b| crate::due_time_cmp(&a.1, &b.1));
+
+    let comparable_len = items
+        .iter()
+        .position(|(_, due_time, _)| due_time.partial_cmp(due_time).is_none())
+        .unwrap_or(items.len());
+
+    let mut heap: BinaryHeap<HeapEntry<P>> = BinaryHeap::new();
+    let mut completion_time = P::default();
+    let mut late = vec![false; comparable_len];
+
+    for (index, (_, due_time, processing_time)) in items.iter().enumerate().take(comparable_len) {
+        let processing_time = processing_time.clone();
+        completion_time = completion_time + processing_time.clone();
+        heap.push(HeapEntry {
+            processing_time,
+            index,
+        });
+
+        if completion_time > *due_time {
+            // Evict the job with the largest processing time seen so far; it need not be the
+            // job that was just pushed.
+            let evicted = heap.pop().expect("just pushed an entry");
+            completion_time = completion_time - evicted.processing_time;
+            late[evicted.index] = true;
+        }
+    }
+
+    let on_time_items = heap.len();
+
+    // Stable-partition the comparable prefix: on-time items keep their relative (due_time)
+    // order at the front, late items follow in whatever order they land in.
+    let mut insert_pos = 0;
+    for i in 0..comparable_len {
+        if !late[i] {
+            if insert_pos != i {
+                items.swap(insert_pos, i);
+                late.swap(insert_pos, i);
+            }
+            insert_pos += 1;
+        }
+    }
+
+    debug_assert_eq!(insert_pos, on_time_items);
+
+    on_time_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn on_time_due_times<T: Clone, D: Clone, P: Clone>(
+        items: &[(T, D, P)],
+        on_time_items: usize,
+    ) -> Vec<D> {
+        items[..on_time_items].iter().map(|(_, d, _)| d.clone()).collect()
+    }
+
+    #[test]
+    fn bsraya_examples() {
+        // https://www.bsraya.com/portfolio/moore-hodgson-algorithm/
+        //
+        // `moore_hodgson` only finds 2 on-time items here (see `tests::bsraya_examples` in
+        // `lib.rs`); the eviction step below recovers the true optimum of 3.
+        let mut jobs = [(1, 6, 4), (2, 7, 3), (5, 11, 6), (4, 9, 5), (3, 8, 2)];
+        let on_time_items = moore_hodgson_heap(&mut jobs);
+        assert_eq!(on_time_items, 3);
+        assert_eq!(on_time_due_times(&jobs, on_time_items), [7, 8, 11]);
+
+        let mut jobs = [
+            (1, 6, 4),
+            (2, 8, 1),
+            (3, 9, 6),
+            (4, 11, 3),
+            (5, 20, 6),
+            (7, 25, 8),
+            (6, 28, 7),
+            (8, 35, 10),
+        ];
+        let on_time_items = moore_hodgson_heap(&mut jobs);
+        assert_eq!(on_time_items, 6);
+        assert_eq!(
+            on_time_due_times(&jobs, on_time_items),
+            [6, 8, 11, 20, 28, 35]
+        );
+    }
+
+    #[test]
+    fn agrees_with_moore_hodgson_on_small_inputs() {
+        // Both algorithms keep the same number of items here. In the first input the third item
+        // overflows: `moore_hodgson` rejects it, while the eviction drops the longer second item
+        // instead, leaving one late item either way.
+        let inputs: [&[(usize, i32, i32)]; 2] = [
+            &[(1, 0, 0), (2, 5, 5), (3, 6, 2)],
+            &[(1, 5, 0), (2, 5, 5)],
+        ];
+
+        for input in inputs {
+            let mut a = input.to_vec();
+            let mut b = input.to_vec();
+            assert_eq!(crate::moore_hodgson(&mut a), moore_hodgson_heap(&mut b));
+        }
+    }
+
+    #[test]
+    fn nan_due_time_goes_late() {
+        let mut jobs = [(1, f32::NAN, 3.), (2, 7., 6.)];
+        assert_eq!(moore_hodgson_heap(&mut jobs), 1);
+        assert_eq!(jobs[0].0, 2);
+    }
+}
@@ -5,6 +5,13 @@
 //!
 //! It provides a single function [`moore_hodgson`], that performs the algorithm.
 //!
+//! With the `alloc` feature enabled, an `O(n log n)` heap-based variant is also available as
+//! [`moore_hodgson_heap`], as well as a weighted variant, [`moore_hodgson_weighted`], that
+//! minimizes the total weight of late items instead of their count, a tabu-search refiner,
+//! [`moore_hodgson_tabu`], for sequence-dependent setup times, [`moore_hodgson_release`] for
+//! items with a release time / availability window, and [`moore_hodgson_parallel`] for
+//! partitioning items across several identical machines.
+//!
 //! # Examples
 //!
 //! ```
@@ -42,8 +49,57 @@
 #![no_std]
 #![warn(missing_docs)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::ops::Add;
 
+/// Order by `key`, treating values that are incomparable with themselves (e.g. [`f32::NAN`]) as
+/// strictly greater than every other value, so that they consistently sort to the back.
+///
+/// Plain `partial_cmp(..).unwrap_or(Ordering::Greater)` is not antisymmetric for such values
+/// (both `cmp(a, b)` and `cmp(b, a)` come back `Greater`), which can leave them anywhere in the
+/// slice instead of at the end.
+#[cfg(feature = "alloc")]
+pub(crate) fn due_time_cmp<D: PartialOrd>(a: &D, b: &D) -> core::cmp::Ordering {
+    use core::cmp::Ordering;
+
+    let a_is_incomparable = a.partial_cmp(a).is_none();
+    let b_is_incomparable = b.partial_cmp(b).is_none();
+
+    match (a_is_incomparable, b_is_incomparable) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod heap;
+#[cfg(feature = "alloc")]
+pub use heap::moore_hodgson_heap;
+
+#[cfg(feature = "alloc")]
+mod weighted;
+#[cfg(feature = "alloc")]
+pub use weighted::moore_hodgson_weighted;
+
+#[cfg(feature = "alloc")]
+mod tabu;
+#[cfg(feature = "alloc")]
+pub use tabu::moore_hodgson_tabu;
+
+#[cfg(feature = "alloc")]
+mod release;
+#[cfg(feature = "alloc")]
+pub use release::moore_hodgson_release;
+
+#[cfg(feature = "alloc")]
+mod parallel;
+#[cfg(feature = "alloc")]
+pub use parallel::{moore_hodgson_parallel, MachineSchedule};
+
 /// Run the Moore-Hudgson's Algorithm on the array with items of form `(item, due_time,
 /// processing_time)`. Returns the amount of items that will be on time.
 ///
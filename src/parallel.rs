@@ -0,0 +1,190 @@
+//! Parallel-machine scheduling, extending [`moore_hodgson`](crate::moore_hodgson) to more than a
+//! single machine.
+
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::{Add, Sub};
+
+/// The resulting schedule for a single machine.
+pub struct MachineSchedule<T, D, P> {
+    /// Items assigned to this machine that complete by their due time, in run order.
+    pub on_time: Vec<(T, D, P)>,
+    /// Items assigned to this machine that end up late.
+    pub late: Vec<(T, D, P)>,
+}
+
+/// An entry in the min-heap of machine clocks, ordered smallest-clock-first.
+struct MachineClock<P> {
+    clock: P,
+    machine: usize,
+}
+
+impl<P: PartialEq> PartialEq for MachineClock<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.clock == other.clock
+    }
+}
+
+impl<P: PartialEq> Eq for MachineClock<P> {}
+
+impl<P: PartialOrd> Ord for MachineClock<P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.clock.partial_cmp(&self.clock).unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<P: PartialOrd> PartialOrd for MachineClock<P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Partition `items` of form `(item, due_time, processing_time)` across `machines` identical
+/// parallel machines, minimizing the total amount of late items across all of them.
+///
+/// Items are processed in ascending `due_time` order; each is assigned to whichever machine
+/// currently has the smallest completion time (tracked in a small min-heap of machine clocks).
+/// Whenever that assignment would make the machine's clock overshoot the item's due time, the
+/// longest on-time item on that machine is evicted instead, mirroring the eviction rule of
+/// [`moore_hodgson_heap`](crate::moore_hodgson_heap).
+///
+/// Returns one [`MachineSchedule`] per machine, holding its on-time items (in run order) and its
+/// late items. Returns an empty `Vec` if `machines` is `0`.
+///
+/// Items with a `due_time` that cannot be compared to itself (e.g. [`f32::NAN`]) can never be on
+/// time and are placed straight into a machine's late list, mirroring
+/// [`moore_hodgson_heap`](crate::moore_hodgson_heap)'s NaN handling.
+///
+/// # Complexity
+/// This function runs in `O(n log n + n^2 / machines)` time.
+///
+/// # Feature
+/// This function requires the `alloc` feature.
+pub fn moore_hodgson_parallel<T, D, P>(
+    mut items: Vec<(T, D, P)>,
+    machines: usize,
+) -> Vec<MachineSchedule<T, D, P>>
+where
+    D: Clone + PartialOrd,
+    P: Clone + Add<P, Output = P> + Sub<P, Output = P> + Default + PartialOrd<D> + PartialOrd,
+{
+    if machines == 0 {
+        return Vec::new();
+    }
+
+    items.sort_by(|a, b| crate::due_time_cmp(&a.1, &b.1));
+
+    let comparable_len = items
+        .iter()
+        .position(|(_, due_time, _)| due_time.partial_cmp(due_time).is_none())
+        .unwrap_or(items.len());
+    let incomparable = items.split_off(comparable_len);
+
+    let mut schedules: Vec<MachineSchedule<T, D, P>> = (0..machines)
+        .map(|_| MachineSchedule {
+            on_time: Vec::new(),
+            late: Vec::new(),
+        })
+        .collect();
+
+    let mut clocks: BinaryHeap<MachineClock<P>> = (0..machines)
+        .map(|machine| MachineClock {
+            clock: P::default(),
+            machine,
+        })
+        .collect();
+
+    for (item, due_time, processing_time) in items {
+        let mut clock = clocks.pop().expect("machines > 0");
+
+        let due_time_check = due_time.clone();
+        let mut new_clock = clock.clock.clone() + processing_time.clone();
+
+        let schedule = &mut schedules[clock.machine];
+        schedule.on_time.push((item, due_time, processing_time));
+
+        if new_clock > due_time_check {
+            // Evict the longest on-time item on this machine; it need not be the item that was
+            // just pushed.
+            let (pos, _) = schedule
+                .on_time
+                .iter()
+                .enumerate()
+                .max_by(|(_, a), (_, b)| a.2.partial_cmp(&b.2).unwrap_or(Ordering::Equal))
+                .expect("just pushed an item");
+
+            let evicted = schedule.on_time.remove(pos);
+            new_clock = new_clock - evicted.2.clone();
+            schedule.late.push(evicted);
+        }
+
+        clock.clock = new_clock;
+        clocks.push(clock);
+    }
+
+    // Items with an incomparable due_time never fit the on-time window; spread them round-robin
+    // across machines' late lists instead of piling them all onto one.
+    for (i, (item, due_time, processing_time)) in incomparable.into_iter().enumerate() {
+        schedules[i % machines].late.push((item, due_time, processing_time));
+    }
+
+    schedules
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_machines_is_empty() {
+        let jobs = alloc::vec![(1, 5, 3)];
+        assert!(moore_hodgson_parallel(jobs, 0).is_empty());
+    }
+
+    #[test]
+    fn generous_due_times_keep_everything_on_time() {
+        let jobs = alloc::vec![(1, 100, 10), (2, 100, 10), (3, 100, 10), (4, 100, 10)];
+        let schedules = moore_hodgson_parallel(jobs, 2);
+
+        assert_eq!(schedules.len(), 2);
+        let total_on_time: usize = schedules.iter().map(|s| s.on_time.len()).sum();
+        let total_late: usize = schedules.iter().map(|s| s.late.len()).sum();
+        assert_eq!(total_on_time, 4);
+        assert_eq!(total_late, 0);
+    }
+
+    #[test]
+    fn single_machine_evicts_the_longest_on_time_job() {
+        let jobs = alloc::vec![(1, 3, 3), (2, 6, 3), (3, 6, 1), (4, 6, 1)];
+        let mut schedules = moore_hodgson_parallel(jobs, 1);
+        assert_eq!(schedules.len(), 1);
+
+        let schedule = schedules.remove(0);
+        assert_eq!(schedule.on_time.len(), 3);
+        assert_eq!(schedule.late.len(), 1);
+    }
+
+    #[test]
+    fn every_item_is_placed_exactly_once() {
+        let jobs = alloc::vec![(1, 2, 5), (2, 3, 1), (3, 9, 4), (4, 1, 1), (5, 6, 2)];
+        let schedules = moore_hodgson_parallel(jobs, 3);
+
+        let total: usize = schedules
+            .iter()
+            .map(|s| s.on_time.len() + s.late.len())
+            .sum();
+        assert_eq!(total, 5);
+    }
+
+    #[test]
+    fn nan_due_time_goes_late() {
+        let jobs = alloc::vec![(1, f32::NAN, 3.), (2, 7., 6.)];
+        let schedules = moore_hodgson_parallel(jobs, 1);
+        assert_eq!(schedules.len(), 1);
+        assert_eq!(schedules[0].on_time.len(), 1);
+        assert_eq!(schedules[0].on_time[0].0, 2);
+        assert_eq!(schedules[0].late.len(), 1);
+        assert_eq!(schedules[0].late[0].0, 1);
+    }
+}
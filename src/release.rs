@@ -0,0 +1,138 @@
+//! Release times / availability windows, as opposed to [`moore_hodgson`](crate::moore_hodgson)
+//! assuming every item is available at time zero.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::Add;
+
+/// Run a Moore-Hodgson-style algorithm on the array with items of form `(item, release_time,
+/// due_time, processing_time)`, where an item cannot start before its `release_time`. Returns
+/// the amount of items that will be on time.
+///
+/// Items are processed in `release_time` order; an item's completion time is
+/// `max(completion_time, release_time) + processing_time`. Whenever that overshoots the item's
+/// `due_time`, the item with the largest `processing_time` among all items scheduled so far is
+/// evicted, mirroring the heap-eviction rule of [`moore_hodgson_heap`](crate::moore_hodgson_heap);
+/// this repeats until the last item still scheduled completes by its own `due_time`. Because an evicted item need not be the last one scheduled, the completion time after an
+/// eviction is recomputed by replaying the remaining scheduled items in order rather than by
+/// subtracting the evicted item's processing time: removing an earlier item can leave a release
+/// gap that the later items still have to wait out.
+///
+/// This is a greedy heuristic, not an exact algorithm: release times make the problem NP-hard in
+/// general. It is not exact even when release order agrees with due order.
+///
+/// On return, the on-time items occupy the front of the slice in ascending `release_time` order;
+/// the late items occupy the rest of the slice in an unspecified order.
+///
+/// # Complexity
+/// This function runs in `O(n^2)` time.
+///
+/// # Feature
+/// This function requires the `alloc` feature.
+pub fn moore_hodgson_release<T, D, P>(items: &mut [(T, P, D, P)]) -> usize
+where
+    D: Clone + PartialOrd,
+    P: Clone + Add<P, Output = P> + Default + PartialOrd<D> + PartialOrd,
+{
+    items.sort_by(|a, b| crate::due_time_cmp(&a.1, &b.1));
+
+    // Replay `scheduled` (indices into `items`) in release order to get the completion time of
+    // that exact set.
+    fn completion_time_of<T, D, P>(items: &[(T, P, D, P)], scheduled: &[usize]) -> P
+    where
+        P: Clone + Add<P, Output = P> + Default + PartialOrd,
+    {
+        let mut completion_time = P::default();
+        for &i in scheduled {
+            let release_time = items[i].1.clone();
+            if completion_time < release_time {
+                completion_time = release_time;
+            }
+            completion_time = completion_time + items[i].3.clone();
+        }
+        completion_time
+    }
+
+    let mut late = vec![false; items.len()];
+    // Indices (into `items`) of the items currently considered on time.
+    let mut scheduled: Vec<usize> = Vec::new();
+
+    for i in 0..items.len() {
+        scheduled.push(i);
+
+        // Evicting an earlier item need not bring the last scheduled item back on time (it may
+        // still wait for its own release), so keep evicting until it is.
+        while let Some(&last) = scheduled.last() {
+            if !(completion_time_of(items, &scheduled) > items[last].2) {
+                break;
+            }
+
+            let (pos, &evicted) = scheduled
+                .iter()
+                .enumerate()
+                .max_by(|(_, &a), (_, &b)| {
+                    items[a].3.partial_cmp(&items[b].3).unwrap_or(Ordering::Equal)
+                })
+                .expect("an item was just scheduled");
+
+            scheduled.remove(pos);
+            late[evicted] = true;
+        }
+    }
+
+    // Stable-partition: on-time items keep their relative (release_time) order at the front.
+    let mut insert_pos = 0;
+    for (i, &is_late) in late.iter().enumerate() {
+        if !is_late {
+            if insert_pos != i {
+                items.swap(insert_pos, i);
+            }
+            insert_pos += 1;
+        }
+    }
+
+    insert_pos
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn agreeable_case() {
+        // Release order agrees with due order here, and both jobs fit: job 1 ends at 5, job 2
+        // waits for its release at 10 and ends at 11.
+        let mut jobs = [(1, 0, 5, 5), (2, 10, 12, 1)];
+        assert_eq!(moore_hodgson_release(&mut jobs), 2);
+    }
+
+    #[test]
+    fn release_time_delays_start() {
+        let mut jobs = [(1, 0, 4, 1), (2, 3, 4, 3)];
+        // Job 2 can't start before t=3, so it would complete at 6, missing its due time of 4;
+        // being the largest job scheduled, it is the one evicted.
+        assert_eq!(moore_hodgson_release(&mut jobs), 1);
+        assert_eq!(jobs[0].0, 1);
+    }
+
+    #[test]
+    fn eviction_can_rescue_a_later_job() {
+        // All release times are equal and the jobs are listed in due order, so they are
+        // scheduled in due order. The largest
+        // already-scheduled job gets evicted, not just the one that overflows, recovering an
+        // extra on-time item that a simple reject-on-overflow rule would miss.
+        let mut jobs = [(1, 0, 3, 3), (2, 0, 6, 3), (3, 0, 6, 1), (4, 0, 6, 1)];
+        assert_eq!(moore_hodgson_release(&mut jobs), 3);
+    }
+
+    #[test]
+    fn eviction_of_an_earlier_job_leaves_a_release_gap() {
+        // Agreeable case (release order matches due order). Evicting job 0 leaves job 1
+        // starting at its own release time of 3, not at job 0's old completion time, so job 2
+        // still completes at max(5, 4) + 4 = 9, past its due time of 8: only job 1 is on time.
+        let mut jobs = [(0, 1, 5, 4), (1, 3, 6, 2), (2, 4, 8, 4)];
+        assert_eq!(moore_hodgson_release(&mut jobs), 1);
+        assert_eq!(jobs[0].0, 1);
+    }
+}
@@ -0,0 +1,184 @@
+//! Sequence-dependent setup times, refined with a tabu search.
+//!
+//! Unlike [`moore_hodgson`](crate::moore_hodgson), the completion time of a job here also
+//! depends on which job ran right before it, which rules out the greedy approach: this module
+//! instead searches the space of job orderings directly.
+
+use alloc::vec::Vec;
+use core::ops::Add;
+
+/// How many iterations the reverse of an applied move stays forbidden for.
+const TABU_TENURE: usize = 7;
+
+/// Relocate the job at index `from` to index `to`, shifting everything in between by one.
+/// Calling `relocate(items, to, from)` undoes the move.
+fn relocate<T, D, P>(items: &mut [(T, D, P)], from: usize, to: usize) {
+    if from < to {
+        items[from..=to].rotate_left(1);
+    } else if from > to {
+        items[to..=from].rotate_right(1);
+    }
+}
+
+/// Count the on-time jobs in `items`, in order, under sequence-dependent `setup` times.
+fn on_time_count<T, D, P, F>(items: &[(T, D, P)], setup: &F) -> usize
+where
+    P: Clone + Add<P, Output = P> + Default + PartialOrd<D>,
+    F: Fn(&T, &T) -> P,
+{
+    let mut completion_time = P::default();
+    let mut on_time = 0;
+
+    for i in 0..items.len() {
+        let (item, due_time, processing_time) = &items[i];
+
+        if i > 0 {
+            completion_time = completion_time + setup(&items[i - 1].0, item);
+        }
+        completion_time = completion_time + processing_time.clone();
+
+        if completion_time <= *due_time {
+            on_time += 1;
+        }
+    }
+
+    on_time
+}
+
+/// Reorder the array with items of form `(item, due_time, processing_time)` to minimize the
+/// amount of late items when a `setup(prev, next)` cost is incurred between consecutive jobs.
+/// Returns the amount of items that will be on time under the returned ordering.
+///
+/// Sequence-dependent setup times make this NP-hard in general, so instead of the exact
+/// Moore-Hodgson approach this runs a tabu search: it is seeded with the plain (setup-less)
+/// [`moore_hodgson`](crate::moore_hodgson) ordering, and then repeatedly relocates a single job
+/// to a different position, always moving to the best non-tabu neighbor (aspiration: a move is
+/// taken regardless of its tabu status if it beats the best ordering found so far). The reverse
+/// of every applied move is forbidden for a fixed tenure of `L = 7` iterations, and the search
+/// stops after `iterations` iterations.
+///
+/// # Complexity
+/// Each iteration evaluates `O(n^2)` neighbors at `O(n)` each, so this runs in `O(iterations *
+/// n^3)` time.
+///
+/// # Feature
+/// This function requires the `alloc` feature.
+pub fn moore_hodgson_tabu<T, D, P, F>(items: &mut [(T, D, P)], setup: F, iterations: usize) -> usize
+where
+    D: Clone + PartialOrd,
+    P: Clone + Add<P, Output = P> + Default + PartialOrd<D>,
+    F: Fn(&T, &T) -> P,
+{
+    crate::moore_hodgson(items);
+
+    let n = items.len();
+    let mut current_score = on_time_count(items, &setup);
+    let mut best_score = current_score;
+
+    let mut history: Vec<(usize, usize)> = Vec::new();
+    let mut best_history_len = 0;
+    let mut tabu_list: Vec<((usize, usize), usize)> = Vec::new();
+
+    for _ in 0..iterations {
+        for entry in tabu_list.iter_mut() {
+            entry.1 -= 1;
+        }
+        tabu_list.retain(|&(_, remaining)| remaining > 0);
+
+        let mut best_move: Option<(usize, usize, usize)> = None;
+
+        for from in 0..n {
+            for to in 0..n {
+                if from == to {
+                    continue;
+                }
+
+                relocate(items, from, to);
+                let candidate_score = on_time_count(items, &setup);
+                relocate(items, to, from);
+
+                let is_tabu = tabu_list.iter().any(|&((f, t), _)| f == from && t == to);
+                let aspires = candidate_score > best_score;
+
+                if is_tabu && !aspires {
+                    continue;
+                }
+
+                let is_better = match best_move {
+                    None => true,
+                    Some((_, _, score)) => candidate_score > score,
+                };
+
+                if is_better {
+                    best_move = Some((from, to, candidate_score));
+                }
+            }
+        }
+
+        let (from, to, candidate_score) = match best_move {
+            Some(candidate) => candidate,
+            None => break,
+        };
+
+        relocate(items, from, to);
+        history.push((from, to));
+        current_score = candidate_score;
+
+        for entry in tabu_list.iter_mut() {
+            entry.1 -= 1;
+        }
+        tabu_list.retain(|&(_, remaining)| remaining > 0);
+        tabu_list.push(((to, from), TABU_TENURE));
+
+        if current_score > best_score {
+            best_score = current_score;
+            best_history_len = history.len();
+        }
+    }
+
+    while history.len() > best_history_len {
+        let (from, to) = history.pop().expect("history.len() > best_history_len");
+        relocate(items, to, from);
+    }
+
+    best_score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_setup_improves_on_the_seed() {
+        // With a zero setup cost, the tabu search still relocates its way from the seed's 2
+        // on-time jobs up to the true optimum of 3 (see `heap::tests::bsraya_examples` for the
+        // same input).
+        let mut jobs = [(1, 6, 4), (2, 7, 3), (3, 11, 6), (4, 9, 5), (5, 8, 2)];
+        let on_time = moore_hodgson_tabu(&mut jobs, |_, _| 0, 20);
+        assert_eq!(on_time, 3);
+    }
+
+    #[test]
+    fn setup_depends_on_order() {
+        // Job 2 has no setup cost when it is run right after job 1, but running job 1 after job
+        // 2 pays a setup of 5, which pushes job 1 over its due time.
+        let setup = |prev: &u32, _next: &u32| if *prev == 1 { 0 } else { 5 };
+
+        let mut jobs = [(1u32, 5, 2), (2u32, 5, 3)];
+        let on_time = moore_hodgson_tabu(&mut jobs, setup, 10);
+        assert_eq!(on_time, 2);
+        assert_eq!(jobs[0].0, 1);
+        assert_eq!(jobs[1].0, 2);
+    }
+
+    #[test]
+    fn zero_iterations_keeps_seed_ordering() {
+        let mut jobs = [(1, 6, 4), (2, 7, 3), (3, 11, 6), (4, 9, 5), (5, 8, 2)];
+        let mut seeded = jobs;
+        crate::moore_hodgson(&mut seeded);
+
+        let on_time = moore_hodgson_tabu(&mut jobs, |_, _| 0, 0);
+        assert_eq!(on_time, 2);
+        assert_eq!(jobs, seeded);
+    }
+}
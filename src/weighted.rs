@@ -0,0 +1,140 @@
+//! Weighted tardy-job minimization, as opposed to the unweighted [`moore_hodgson`](crate::moore_hodgson).
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Add;
+
+/// Run a Lawler-Moore style DP on the array with items of form `(item, due_time,
+/// processing_time, weight)` to minimize the total *weight* of late items, rather than their
+/// count. Returns the amount of items that will be on time.
+///
+/// Unlike [`moore_hodgson`](crate::moore_hodgson), `due_time` and `processing_time` are required
+/// to be `usize`: the DP keeps a `best[t]` table indexed by summed processing time, which makes
+/// it pseudo-polynomial rather than strongly polynomial.
+///
+/// On return, the on-time items occupy the front of the slice in ascending `due_time` order; the
+/// late items occupy the rest of the slice in an unspecified order.
+///
+/// # Complexity
+/// This function runs in `O(n * P)` time and space, where `P` is the sum of all `processing_time`s.
+///
+/// # Feature
+/// This function requires the `alloc` feature.
+pub fn moore_hodgson_weighted<T, W>(items: &mut [(T, usize, usize, W)]) -> usize
+where
+    W: Clone + Add<W, Output = W> + PartialOrd + Default,
+{
+    items.sort_by_key(|(_, due_time, _, _)| *due_time);
+
+    let total_processing: usize = items.iter().map(|(_, _, processing_time, _)| *processing_time).sum();
+
+    // best[t] = max total weight of an on-time subset whose summed processing time is exactly t.
+    let mut best: Vec<Option<W>> = vec![None; total_processing + 1];
+    best[0] = Some(W::default());
+
+    // taken[j][t]: whether item j is part of the subset that achieves `best[t]` as of the row
+    // recorded right after item j was processed. Used to backtrack the chosen on-time set.
+    let mut taken: Vec<Vec<bool>> = vec![vec![false; total_processing + 1]; items.len()];
+
+    for (j, (_, due_time, processing_time, weight)) in items.iter().enumerate() {
+        if *processing_time > *due_time {
+            // This item can never be on time, not even on its own.
+            continue;
+        }
+
+        let max_t = (*due_time - *processing_time).min(total_processing - *processing_time);
+
+        for t in (0..=max_t).rev() {
+            let current = match &best[t] {
+                Some(current) => current.clone(),
+                None => continue,
+            };
+
+            let candidate = current + weight.clone();
+            let target = t + *processing_time;
+
+            let is_better = match &best[target] {
+                None => true,
+                Some(existing) => candidate > *existing,
+            };
+
+            if is_better {
+                best[target] = Some(candidate);
+                taken[j][target] = true;
+            }
+        }
+    }
+
+    let mut best_t = 0;
+    for t in 0..best.len() {
+        if best[t] > best[best_t] {
+            best_t = t;
+        }
+    }
+
+    let mut on_time = vec![false; items.len()];
+    let mut t = best_t;
+    for j in (0..items.len()).rev() {
+        if taken[j][t] {
+            on_time[j] = true;
+            t -= items[j].2;
+        }
+    }
+
+    let on_time_items = on_time.iter().filter(|x| **x).count();
+
+    // Stable-partition: on-time items keep their relative (due_time) order at the front.
+    let mut insert_pos = 0;
+    for (i, &is_on_time) in on_time.iter().enumerate() {
+        if is_on_time {
+            if insert_pos != i {
+                items.swap(insert_pos, i);
+            }
+            insert_pos += 1;
+        }
+    }
+
+    on_time_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn favors_heavier_jobs() {
+        // Two jobs both due at 1, each taking 1 time unit: only one can be on time. The
+        // heavier-weighted one should be kept.
+        let mut jobs = [(1, 1, 1, 1u32), (2, 1, 1, 10u32)];
+        assert_eq!(moore_hodgson_weighted(&mut jobs), 1);
+        assert_eq!(jobs[0].0, 2);
+    }
+
+    #[test]
+    fn uniform_weights_maximizes_on_time_count() {
+        // With every weight equal, maximizing total weight is the same as maximizing the count
+        // of on-time items. This DP finds the true optimum of 3 here, one better than the
+        // `moore_hodgson` baseline's 2 (see `heap::tests::bsraya_examples` for the same input).
+        let mut jobs = [
+            (1, 6, 4, 1u32),
+            (2, 7, 3, 1u32),
+            (3, 11, 6, 1u32),
+            (4, 9, 5, 1u32),
+            (5, 8, 2, 1u32),
+        ];
+        assert_eq!(moore_hodgson_weighted(&mut jobs), 3);
+    }
+
+    #[test]
+    fn impossible_job_never_on_time() {
+        let mut jobs = [(1, 2, 5, 100u32), (2, 5, 3, 1u32)];
+        assert_eq!(moore_hodgson_weighted(&mut jobs), 1);
+        assert_eq!(jobs[0].0, 2);
+    }
+
+    #[test]
+    fn empty() {
+        let mut jobs: [(usize, usize, usize, u32); 0] = [];
+        assert_eq!(moore_hodgson_weighted(&mut jobs), 0);
+    }
+}